@@ -0,0 +1,207 @@
+// Read-only HTTP front end over a `GameGraph` loaded once at startup, so the
+// analytics in main.rs can be explored without recompiling or re-parsing the
+// CSV on every query.
+use actix_cors::Cors;
+use actix_web::{web, App, HttpResponse, HttpServer};
+use serde::Serialize;
+use std::error::Error;
+
+use crate::{buildGraph, GameGraph};
+
+#[derive(Serialize)]
+struct WeightedNeighbor {
+    name: String,
+    weight: f32,
+}
+
+#[derive(Serialize)]
+struct NeighborsResponse {
+    game: String,
+    neighbors: Vec<WeightedNeighbor>,
+}
+
+#[derive(Serialize)]
+struct DistanceResponse {
+    from: String,
+    to: String,
+    hops: Option<usize>,
+    weightedDistance: Option<f32>,
+}
+
+#[derive(Serialize)]
+struct DegreeCentralityResponse {
+    centrality: Vec<(String, usize)>,
+}
+
+#[derive(Serialize)]
+struct ComponentsResponse {
+    componentCount: usize,
+    componentSizes: Vec<usize>,
+}
+
+async fn getNeighbors(graph: web::Data<GameGraph>, path: web::Path<String>) -> HttpResponse {
+    let game = path.into_inner();
+    match graph.adjList.get(&game) {
+        Some(neighbors) => {
+            let neighbors = neighbors
+                .iter()
+                .map(|(name, weight)| WeightedNeighbor {
+                    name: name.clone(),
+                    weight: *weight,
+                })
+                .collect();
+            HttpResponse::Ok().json(NeighborsResponse { game, neighbors })
+        }
+        None => HttpResponse::NotFound().body(format!("no such game: {}", game)),
+    }
+}
+
+async fn getDistance(graph: web::Data<GameGraph>, path: web::Path<(String, String)>) -> HttpResponse {
+    let (from, to) = path.into_inner();
+    if !graph.adjList.contains_key(&from) {
+        return HttpResponse::NotFound().body(format!("no such game: {}", from));
+    }
+
+    let hops = graph.bfs(&from).get(&to).copied();
+    let weightedDistance = graph.dijkstra(&from).get(&to).copied();
+    HttpResponse::Ok().json(DistanceResponse {
+        from,
+        to,
+        hops,
+        weightedDistance,
+    })
+}
+
+async fn getDegreeCentrality(graph: web::Data<GameGraph>) -> HttpResponse {
+    let centrality = graph.degreeCentrality().into_iter().collect();
+    HttpResponse::Ok().json(DegreeCentralityResponse { centrality })
+}
+
+async fn getComponents(graph: web::Data<GameGraph>) -> HttpResponse {
+    let components = graph.connectedComponents();
+    let componentSizes = components.iter().map(|c| c.len()).collect();
+    HttpResponse::Ok().json(ComponentsResponse {
+        componentCount: components.len(),
+        componentSizes,
+    })
+}
+
+pub(crate) async fn run(filePath: &str) -> Result<(), Box<dyn Error>> {
+    let graph = buildGraph(filePath)?;
+    let graph = web::Data::new(graph);
+
+    HttpServer::new(move || {
+        App::new()
+            .wrap(Cors::permissive())
+            .app_data(graph.clone())
+            .route("/neighbors/{game}", web::get().to(getNeighbors))
+            .route("/distance/{from}/{to}", web::get().to(getDistance))
+            .route("/centrality/degree", web::get().to(getDegreeCentrality))
+            .route("/components", web::get().to(getComponents))
+    })
+    .bind(("127.0.0.1", 8080))?
+    .run()
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+
+    fn testGraph() -> GameGraph {
+        let mut graph = GameGraph::new();
+        graph.addEdge("Game1", "Game2", 1.5);
+        graph.addEdge("Game2", "Game3", 2.0);
+        graph
+    }
+
+    #[actix_web::test]
+    async fn testGetNeighborsUnknownGameReturns404() {
+        let graph = web::Data::new(testGraph());
+        let app = test::init_service(
+            App::new().app_data(graph.clone()).route("/neighbors/{game}", web::get().to(getNeighbors)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/neighbors/NoSuchGame").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn testGetNeighborsHappyPath() {
+        let graph = web::Data::new(testGraph());
+        let app = test::init_service(
+            App::new().app_data(graph.clone()).route("/neighbors/{game}", web::get().to(getNeighbors)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/neighbors/Game1").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+        assert!(body.contains("Game2"));
+    }
+
+    #[actix_web::test]
+    async fn testGetDistanceUnknownGameReturns404() {
+        let graph = web::Data::new(testGraph());
+        let app = test::init_service(
+            App::new().app_data(graph.clone()).route("/distance/{from}/{to}", web::get().to(getDistance)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/distance/NoSuchGame/Game1").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn testGetDistanceHappyPath() {
+        let graph = web::Data::new(testGraph());
+        let app = test::init_service(
+            App::new().app_data(graph.clone()).route("/distance/{from}/{to}", web::get().to(getDistance)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/distance/Game1/Game3").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+        assert!(body.contains("\"hops\":2"));
+    }
+
+    #[actix_web::test]
+    async fn testGetDegreeCentralityHappyPath() {
+        let graph = web::Data::new(testGraph());
+        let app = test::init_service(
+            App::new().app_data(graph.clone()).route("/centrality/degree", web::get().to(getDegreeCentrality)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/centrality/degree").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+        assert!(body.contains("Game2"));
+    }
+
+    #[actix_web::test]
+    async fn testGetComponentsHappyPath() {
+        let graph = web::Data::new(testGraph());
+        let app = test::init_service(
+            App::new().app_data(graph.clone()).route("/components", web::get().to(getComponents)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/components").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+        assert!(body.contains("\"componentCount\":1"));
+    }
+}